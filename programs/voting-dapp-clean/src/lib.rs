@@ -1,10 +1,15 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 declare_id!("GW1r76tkZDNpdKf7BD7ap1EtPvnQb592apWuaKWCyckd");
 
 const MAX_QUESTION_LENGTH: usize = 200;
 const MAX_OPTION_LENGTH: usize = 30;
 const MAX_OPTIONS: usize = 4;
+const MAX_CONVICTION: u8 = 6;
+const CONVICTION_SCALE: u64 = 10;
+const BASE_LOCK_PERIOD: i64 = 60 * 60 * 24; // one day
+const MAX_VOTE_HISTORY: usize = 8;
 
 #[program]
 pub mod voting_platform {
@@ -16,6 +21,9 @@ pub mod voting_platform {
         question: String,
         options: Vec<String>,
         duration: i64,
+        weighted: bool,
+        secret: bool,
+        vote_mode: VoteMode,
     ) -> Result<()> {
         // Validate inputs
         require!(question.len() <= MAX_QUESTION_LENGTH, VotingError::QuestionTooLong);
@@ -26,11 +34,20 @@ pub mod voting_platform {
             require!(option.len() <= MAX_OPTION_LENGTH, VotingError::OptionTooLong);
         }
 
+        // Secret ballots only ever tally one vote per revealed wallet, so they
+        // cannot express token weights; reject the contradictory combination.
+        require!(!(weighted && secret), VotingError::WeightedSecretUnsupported);
+
         let poll = &mut ctx.accounts.poll;
         
         poll.poll_id = poll_id;
         poll.creator = ctx.accounts.creator.key();
         poll.created_at = Clock::get()?.unix_timestamp;
+        poll.end_time = poll.created_at + duration;
+        poll.finalized = false;
+        poll.weighted = weighted;
+        poll.secret = secret;
+        poll.vote_mode = vote_mode;
         poll.option_count = options.len() as u8;
         
         // Copy question to fixed-size array
@@ -50,35 +67,348 @@ pub mod voting_platform {
 
     pub fn cast_vote(
         ctx: Context<CastVote>,
+        option_indices: Vec<u8>,
+        conviction: u8,
+    ) -> Result<()> {
+        let poll = &mut ctx.accounts.poll;
+        let voter_record = &mut ctx.accounts.voter_record;
+        let clock = Clock::get()?;
+
+        // Single-choice polls accept exactly one option; approval polls accept
+        // several distinct options at once (bounded by MAX_OPTIONS).
+        require!(!option_indices.is_empty(), VotingError::InvalidOption);
+        match poll.vote_mode {
+            VoteMode::Single => {
+                require!(option_indices.len() == 1, VotingError::TooManySelections);
+            }
+            VoteMode::Approval => {
+                require!(
+                    option_indices.len() <= poll.option_count as usize,
+                    VotingError::TooManySelections
+                );
+            }
+        }
+
+        let mut seen_mask: u8 = 0;
+        for &index in &option_indices {
+            require!(
+                (index as usize) < poll.option_count as usize,
+                VotingError::InvalidOption
+            );
+            let bit = 1u8 << index;
+            require!(seen_mask & bit == 0, VotingError::DuplicateSelection);
+            seen_mask |= bit;
+        }
+
+        require!(!poll.secret, VotingError::SecretBallot);
+        require!(!poll.finalized, VotingError::PollClosed);
+        require!(
+            clock.unix_timestamp < poll.end_time,
+            VotingError::PollClosed
+        );
+
+        require!(!voter_record.has_voted, VotingError::AlreadyVoted);
+
+        require!(conviction <= MAX_CONVICTION, VotingError::InvalidConviction);
+        // Conviction multiplies a real locked balance; without a deposit it
+        // would just hand non-weighted voters a free multiplier, so it is only
+        // meaningful on weighted polls.
+        require!(
+            conviction == 0 || poll.weighted,
+            VotingError::ConvictionRequiresWeight
+        );
+        // A weighted deposit must actually lock; otherwise the escrow could be
+        // released the instant the vote lands and recycled into another wallet.
+        require!(
+            !poll.weighted || conviction >= 1,
+            VotingError::WeightedNeedsConviction
+        );
+
+        // In weighted mode the locked balance is the SPL amount recorded in the
+        // voter's VoterWeightRecord PDA; otherwise it is a flat one-wallet stake.
+        let locked_amount: u64 = if poll.weighted {
+            let weight_record = ctx
+                .accounts
+                .voter_weight_record
+                .as_ref()
+                .ok_or(VotingError::WeightRecordMissing)?;
+            require!(
+                weight_record.voter == ctx.accounts.voter.key(),
+                VotingError::Unauthorized
+            );
+            require!(weight_record.poll == poll.key(), VotingError::Unauthorized);
+            weight_record.weight
+        } else {
+            1
+        };
+
+        // Conviction multiplier held in fixed point (scaled by CONVICTION_SCALE):
+        // level 0 is 0.1x (no lock), levels 1..6 are 1x..6x.
+        let multiplier: u64 = if conviction == 0 {
+            1
+        } else {
+            (conviction as u64) * CONVICTION_SCALE
+        };
+        let weight = locked_amount
+            .checked_mul(multiplier)
+            .ok_or(VotingError::WeightOverflow)?;
+
+        // The deposit stays locked for base_lock * 2^(conviction - 1); level 0
+        // carries no conviction lock. Either way the escrow is held at least
+        // until the poll closes so a deposit can never be voted then recycled
+        // into a second wallet while the poll is still live.
+        let conviction_unlock = if conviction == 0 {
+            clock.unix_timestamp
+        } else {
+            clock.unix_timestamp + BASE_LOCK_PERIOD * (1i64 << (conviction - 1))
+        };
+        let unlock_at = conviction_unlock.max(poll.end_time);
+
+        for &index in &option_indices {
+            poll.votes[index as usize] = poll.votes[index as usize]
+                .checked_add(weight)
+                .ok_or(VotingError::WeightOverflow)?;
+
+            emit!(VoteCast {
+                poll: poll.key(),
+                voter: ctx.accounts.voter.key(),
+                option_index: index,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        voter_record.has_voted = true;
+        voter_record.voted_option = option_indices[0];
+        voter_record.weight_applied = weight;
+        voter_record.conviction = conviction;
+        voter_record.unlock_at = unlock_at;
+        voter_record.voted_at = clock.unix_timestamp;
+        voter_record.voter = ctx.accounts.voter.key();
+        voter_record.poll = poll.key();
+
+        Ok(())
+    }
+
+    pub fn change_vote(
+        ctx: Context<ChangeVote>,
         option_index: u8,
     ) -> Result<()> {
         let poll = &mut ctx.accounts.poll;
         let voter_record = &mut ctx.accounts.voter_record;
         let clock = Clock::get()?;
-            
+
+        require!(!poll.secret, VotingError::SecretBallot);
+        // Approval ballots spread weight across several options, which this
+        // single-option rebalance cannot represent without corrupting the tally.
+        require!(
+            poll.vote_mode == VoteMode::Single,
+            VotingError::ApprovalImmutable
+        );
+        require!(!poll.finalized, VotingError::PollClosed);
+        require!(
+            clock.unix_timestamp < poll.end_time,
+            VotingError::PollClosed
+        );
+        require!(voter_record.has_voted, VotingError::NotVoted);
         require!(
             (option_index as usize) < poll.option_count as usize,
             VotingError::InvalidOption
         );
 
+        let old_option = voter_record.voted_option;
+        let weight = voter_record.weight_applied;
+
+        // Move the voter's existing weight from their old choice to the new one.
+        poll.votes[old_option as usize] =
+            poll.votes[old_option as usize].saturating_sub(weight);
+        poll.votes[option_index as usize] = poll.votes[option_index as usize]
+            .checked_add(weight)
+            .ok_or(VotingError::WeightOverflow)?;
+
+        voter_record.voted_option = option_index;
+        voter_record.voted_at = clock.unix_timestamp;
+        voter_record.record_change(option_index, clock.unix_timestamp);
+
+        emit!(VoteChanged {
+            poll: poll.key(),
+            voter: ctx.accounts.voter.key(),
+            old_option,
+            new_option: option_index,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn commit_vote(
+        ctx: Context<CommitVote>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        let poll = &mut ctx.accounts.poll;
+        let voter_record = &mut ctx.accounts.voter_record;
+        let clock = Clock::get()?;
+
+        require!(poll.secret, VotingError::NotSecretBallot);
+        require!(!poll.finalized, VotingError::PollClosed);
+        require!(
+            clock.unix_timestamp < poll.end_time,
+            VotingError::PollClosed
+        );
         require!(!voter_record.has_voted, VotingError::AlreadyVoted);
-        
 
-        poll.votes[option_index as usize] += 1;
-        
         voter_record.has_voted = true;
-        voter_record.voted_option = option_index;
+        voter_record.commitment = commitment;
         voter_record.voted_at = clock.unix_timestamp;
         voter_record.voter = ctx.accounts.voter.key();
         voter_record.poll = poll.key();
-        
+
+        poll.committed_count = poll
+            .committed_count
+            .checked_add(1)
+            .ok_or(VotingError::WeightOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        option_index: u8,
+        nonce: [u8; 32],
+    ) -> Result<()> {
+        let poll = &mut ctx.accounts.poll;
+        let voter_record = &mut ctx.accounts.voter_record;
+        let clock = Clock::get()?;
+
+        require!(poll.secret, VotingError::NotSecretBallot);
+        require!(
+            clock.unix_timestamp >= poll.end_time,
+            VotingError::RevealNotOpen
+        );
+        require!(voter_record.has_voted, VotingError::NotCommitted);
+        require!(!voter_record.revealed, VotingError::AlreadyVoted);
+        require!(
+            (option_index as usize) < poll.option_count as usize,
+            VotingError::InvalidOption
+        );
+
+        // Re-derive the commitment and verify it matches what was stored in the
+        // commit phase: hash(option_index || nonce || voter_pubkey).
+        let computed = anchor_lang::solana_program::keccak::hashv(&[
+            &[option_index],
+            &nonce,
+            ctx.accounts.voter.key().as_ref(),
+        ]);
+        require!(
+            computed.0 == voter_record.commitment,
+            VotingError::CommitmentMismatch
+        );
+
+        // Tally in the same fixed-point scale as weighted/conviction votes so
+        // magnitudes across modes stay comparable: one ballot == one 1x vote.
+        poll.votes[option_index as usize] = poll.votes[option_index as usize]
+            .checked_add(CONVICTION_SCALE)
+            .ok_or(VotingError::WeightOverflow)?;
+        poll.revealed_count = poll
+            .revealed_count
+            .checked_add(1)
+            .ok_or(VotingError::WeightOverflow)?;
+
+        voter_record.revealed = true;
+        voter_record.voted_option = option_index;
+
         emit!(VoteCast {
             poll: poll.key(),
             voter: ctx.accounts.voter.key(),
             option_index,
             timestamp: clock.unix_timestamp,
         });
-        
+
+        Ok(())
+    }
+
+    pub fn register_voter_weight(
+        ctx: Context<RegisterVoterWeight>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, VotingError::InvalidDeposit);
+
+        // Escrow the voter's tokens into the program-owned vault; their poll
+        // weight is exactly the amount actually transferred, never a self-
+        // declared figure.
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.voter_token_account.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+            authority: ctx.accounts.voter.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+        );
+        token::transfer(cpi_ctx, amount)?;
+
+        let weight_record = &mut ctx.accounts.voter_weight_record;
+        weight_record.voter = ctx.accounts.voter.key();
+        weight_record.poll = ctx.accounts.poll.key();
+        weight_record.mint = ctx.accounts.mint.key();
+        weight_record.weight = amount;
+
+        Ok(())
+    }
+
+    pub fn release_voter_weight(ctx: Context<ReleaseVoterWeight>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require!(
+            clock.unix_timestamp >= ctx.accounts.voter_record.unlock_at,
+            VotingError::DepositLocked
+        );
+        // Never release while the poll is still accepting votes.
+        require!(
+            ctx.accounts.poll.finalized
+                || clock.unix_timestamp >= ctx.accounts.poll.end_time,
+            VotingError::DepositLocked
+        );
+
+        // Return the full escrowed balance to the voter, signed by the vault
+        // authority PDA, then zero out the recorded weight.
+        let amount = ctx.accounts.vault.amount;
+        if amount > 0 {
+            let poll_key = ctx.accounts.poll.key();
+            let authority_seeds: &[&[u8]] = &[
+                b"vault-authority",
+                poll_key.as_ref(),
+                &[ctx.bumps.vault_authority],
+            ];
+            let signer_seeds = &[authority_seeds];
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.voter_token_account.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, amount)?;
+        }
+
+        ctx.accounts.voter_weight_record.weight = 0;
+
+        Ok(())
+    }
+
+    pub fn close_poll(ctx: Context<ClosePoll>) -> Result<()> {
+        let poll = &mut ctx.accounts.poll;
+
+        require!(
+            ctx.accounts.creator.key() == poll.creator,
+            VotingError::Unauthorized
+        );
+        require!(!poll.finalized, VotingError::PollClosed);
+
+        poll.finalized = true;
+
         Ok(())
     }
 
@@ -102,6 +432,42 @@ pub struct CreatePoll<'info> {
 
 #[derive(Accounts)]
 pub struct CastVote<'info> {
+    #[account(mut)]
+    pub poll: Account<'info, Poll>,
+    #[account(
+        init,
+        payer = voter,
+        space = VoterRecord::LEN,
+        seeds = [b"voter", poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    #[account(
+        seeds = [b"voter-weight", poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_weight_record: Option<Account<'info, VoterWeightRecord>>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    #[account(mut)]
+    pub poll: Account<'info, Poll>,
+    #[account(
+        mut,
+        seeds = [b"voter", poll.key().as_ref(), voter.key().as_ref()],
+        bump,
+        constraint = voter_record.voter == voter.key() @ VotingError::Unauthorized
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
     #[account(mut)]
     pub poll: Account<'info, Poll>,
     #[account(
@@ -117,6 +483,96 @@ pub struct CastVote<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    #[account(mut)]
+    pub poll: Account<'info, Poll>,
+    #[account(
+        mut,
+        seeds = [b"voter", poll.key().as_ref(), voter.key().as_ref()],
+        bump,
+        constraint = voter_record.voter == voter.key() @ VotingError::Unauthorized
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterVoterWeight<'info> {
+    pub poll: Account<'info, Poll>,
+    #[account(
+        init,
+        payer = voter,
+        space = VoterWeightRecord::LEN,
+        seeds = [b"voter-weight", poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+    #[account(
+        init,
+        payer = voter,
+        seeds = [b"vault", poll.key().as_ref(), voter.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vault_authority
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    /// CHECK: PDA that owns the vault token account; never signs from the client.
+    #[account(
+        seeds = [b"vault-authority", poll.key().as_ref()],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == voter.key() @ VotingError::Unauthorized,
+        constraint = voter_token_account.mint == mint.key() @ VotingError::Unauthorized
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseVoterWeight<'info> {
+    pub poll: Account<'info, Poll>,
+    #[account(
+        seeds = [b"voter", poll.key().as_ref(), voter.key().as_ref()],
+        bump,
+        constraint = voter_record.voter == voter.key() @ VotingError::Unauthorized
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    #[account(
+        mut,
+        seeds = [b"voter-weight", poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+    #[account(
+        mut,
+        seeds = [b"vault", poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    /// CHECK: PDA that owns the vault token account; only signs via seeds here.
+    #[account(
+        seeds = [b"vault-authority", poll.key().as_ref()],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == voter.key() @ VotingError::Unauthorized
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    pub voter: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
 #[derive(Accounts)]
 pub struct ClosePoll<'info> {
     #[account(mut)]
@@ -135,6 +591,13 @@ pub struct Poll {
     pub votes: [u64; MAX_OPTIONS],                       // 32 bytes (8 * 4)
     pub option_count: u8,                                // 1 byte
     pub created_at: i64,                                 // 8 bytes
+    pub end_time: i64,                                   // 8 bytes
+    pub finalized: bool,                                 // 1 byte
+    pub weighted: bool,                                  // 1 byte
+    pub secret: bool,                                    // 1 byte
+    pub committed_count: u64,                            // 8 bytes
+    pub revealed_count: u64,                             // 8 bytes
+    pub vote_mode: VoteMode,                             // 1 byte
 }
 impl Poll {
     pub const LEN: usize = 8 + // discriminator
@@ -146,7 +609,14 @@ impl Poll {
     MAX_OPTIONS + // option_lengths
     (8 * MAX_OPTIONS) + // votes
     1 + // option_count
-    8; // created_at
+    8 + // created_at
+    8 + // end_time
+    1 + // finalized
+    1 + // weighted
+    1 + // secret
+    8 + // committed_count
+    8 + // revealed_count
+    1; // vote_mode
 
     pub fn get_question(&self) -> String {
         String::from_utf8_lossy(&self.question[..self.question_length as usize]).to_string()
@@ -177,6 +647,14 @@ pub struct VoterRecord {
     pub voted_at: i64,      // 8 bytes
     pub voter: Pubkey,      // 32 bytes
     pub poll: Pubkey,       // 32 bytes
+    pub weight_applied: u64, // 8 bytes
+    pub conviction: u8,     // 1 byte
+    pub unlock_at: i64,     // 8 bytes
+    pub commitment: [u8; 32], // 32 bytes
+    pub revealed: bool,     // 1 byte
+    pub history: [VoteHistoryEntry; MAX_VOTE_HISTORY], // 9 * 8 bytes
+    pub history_head: u8,   // 1 byte
+    pub history_len: u8,    // 1 byte
 }
 
 impl VoterRecord {
@@ -185,7 +663,58 @@ impl VoterRecord {
         1 + // voted_option
         8 + // voted_at
         32 + // voter
-        32; // poll
+        32 + // poll
+        8 + // weight_applied
+        1 + // conviction
+        8 + // unlock_at
+        32 + // commitment
+        1 + // revealed
+        (VoteHistoryEntry::LEN * MAX_VOTE_HISTORY) + // history
+        1 + // history_head
+        1; // history_len
+
+    /// Append a vote change to the fixed-size ring buffer, overwriting the
+    /// oldest entry once `MAX_VOTE_HISTORY` is reached.
+    pub fn record_change(&mut self, option: u8, ts: i64) {
+        self.history[self.history_head as usize] = VoteHistoryEntry { option, ts };
+        self.history_head = ((self.history_head as usize + 1) % MAX_VOTE_HISTORY) as u8;
+        if (self.history_len as usize) < MAX_VOTE_HISTORY {
+            self.history_len += 1;
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VoteMode {
+    #[default]
+    Single,
+    Approval,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct VoteHistoryEntry {
+    pub option: u8, // 1 byte
+    pub ts: i64,    // 8 bytes
+}
+
+impl VoteHistoryEntry {
+    pub const LEN: usize = 1 + 8;
+}
+
+#[account]
+pub struct VoterWeightRecord {
+    pub voter: Pubkey,  // 32 bytes
+    pub poll: Pubkey,   // 32 bytes
+    pub mint: Pubkey,   // 32 bytes
+    pub weight: u64,    // 8 bytes
+}
+
+impl VoterWeightRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // voter
+        32 + // poll
+        32 + // mint
+        8; // weight
 }
 
 #[event]
@@ -196,6 +725,15 @@ pub struct VoteCast {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct VoteChanged {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub old_option: u8,
+    pub new_option: u8,
+    pub timestamp: i64,
+}
+
 #[error_code]
 pub enum VotingError {
     #[msg("Insufficient options provided. At least 2 options required.")]
@@ -212,4 +750,40 @@ pub enum VotingError {
     AlreadyVoted,
     #[msg("Unauthorized action.")]
     Unauthorized,
+    #[msg("Poll is closed. Voting is no longer allowed.")]
+    PollClosed,
+    #[msg("Weighted poll requires a VoterWeightRecord account.")]
+    WeightRecordMissing,
+    #[msg("Invalid conviction level. Must be between 0 and 6.")]
+    InvalidConviction,
+    #[msg("Vote weight calculation overflowed.")]
+    WeightOverflow,
+    #[msg("Deposit is still locked and cannot be released yet.")]
+    DepositLocked,
+    #[msg("This poll uses secret ballots. Use commit_vote and reveal_vote.")]
+    SecretBallot,
+    #[msg("This poll does not use secret ballots.")]
+    NotSecretBallot,
+    #[msg("Reveal phase is not open yet. Wait for the poll to end.")]
+    RevealNotOpen,
+    #[msg("No committed ballot found to reveal.")]
+    NotCommitted,
+    #[msg("Revealed vote does not match the stored commitment.")]
+    CommitmentMismatch,
+    #[msg("No existing vote to change.")]
+    NotVoted,
+    #[msg("Too many options selected for this poll.")]
+    TooManySelections,
+    #[msg("The same option was selected more than once.")]
+    DuplicateSelection,
+    #[msg("Deposit amount must be greater than zero.")]
+    InvalidDeposit,
+    #[msg("Weighted secret ballots are not supported.")]
+    WeightedSecretUnsupported,
+    #[msg("Vote changes are not allowed on approval polls.")]
+    ApprovalImmutable,
+    #[msg("Conviction voting requires a weighted poll with a locked balance.")]
+    ConvictionRequiresWeight,
+    #[msg("Weighted votes must lock with a conviction level of at least 1.")]
+    WeightedNeedsConviction,
 }
\ No newline at end of file